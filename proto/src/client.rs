@@ -1,33 +1,169 @@
+use std::collections::HashMap;
 use std::convert::TryInto;
 use std::error::Error as StdError;
 use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
 
 use crate::ConversionError;
 
-use tokio::sync::mpsc;
-use tokio_stream::wrappers::ReceiverStream;
+use tokio::sync::{mpsc, oneshot, Mutex};
+use tokio_stream::wrappers::{ReceiverStream, UnboundedReceiverStream};
+use tokio_stream::{Stream, StreamExt};
 use tonic::codec::Streaming;
 use tonic::transport::{Channel, Endpoint, Error as TonicTransportError};
 use tonic::{Request, Status};
 use uuid::Uuid;
 
+#[cfg(feature = "metrics")]
+use opentelemetry::metrics::Meter;
+
+/// Default capacity of the `mpsc` channels used to feed `bulk_insert` and
+/// `transaction` request streams. Override per-client via
+/// `ClientBuilder::channel_capacity`.
 const CHANNEL_CAPACITY: usize = 100;
 
-fn check_request_id(expected: u32, actual: u32) -> Result<(), ClientError> {
-    if expected != actual {
-        Err(ClientError::UnexpectedResponseId { expected, actual })
-    } else {
-        Ok(())
+/// The error returned if a connection-level `Client` operation failed, i.e.
+/// one that only talks to the server directly and never multiplexes
+/// per-request responses (`Client::new`, `ping`, `sync`, `bulk_insert`,
+/// `index_property`, opening a `transaction`).
+#[derive(Debug)]
+pub enum TransportError {
+    /// A gRPC error.
+    Grpc { inner: Status },
+    /// A transport error.
+    Transport { inner: TonicTransportError },
+    /// The gRPC channel has been closed.
+    ChannelClosed,
+}
+
+impl StdError for TransportError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match *self {
+            TransportError::Grpc { ref inner } => Some(inner),
+            TransportError::Transport { ref inner } => Some(inner),
+            TransportError::ChannelClosed => None,
+        }
     }
 }
 
-/// The error returned if a client operation failed.
+impl fmt::Display for TransportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TransportError::Grpc { ref inner } => write!(f, "grpc error: {}", inner),
+            TransportError::Transport { ref inner } => write!(f, "transport error: {}", inner),
+            TransportError::ChannelClosed => write!(f, "failed to send request: channel closed"),
+        }
+    }
+}
+
+impl From<Status> for TransportError {
+    fn from(err: Status) -> Self {
+        TransportError::Grpc { inner: err }
+    }
+}
+
+impl From<TonicTransportError> for TransportError {
+    fn from(err: TonicTransportError) -> Self {
+        TransportError::Transport { inner: err }
+    }
+}
+
+impl<T> From<mpsc::error::SendError<T>> for TransportError {
+    fn from(_: mpsc::error::SendError<T>) -> Self {
+        TransportError::ChannelClosed
+    }
+}
+
+#[cfg(feature = "metrics")]
+impl TransportError {
+    /// Labels this error for the metrics `outcome` dimension.
+    fn metrics_outcome(&self) -> &'static str {
+        match self {
+            TransportError::Grpc { .. } => "grpc-error",
+            TransportError::Transport { .. } | TransportError::ChannelClosed => "transport-error",
+        }
+    }
+}
+
+/// The error returned if a per-operation `Transaction` request failed, i.e.
+/// one that's demultiplexed off the transaction's response stream by
+/// `request_id`.
+#[derive(Debug)]
+pub enum RequestError {
+    /// Conversion between an IndraDB and its protobuf equivalent failed.
+    Conversion { inner: ConversionError },
+    /// A gRPC stream response had an unexpected empty body, implying a bug.
+    UnexpectedEmptyResponse { request_id: u32 },
+    /// A gRPC error.
+    Grpc { inner: Status },
+    /// The gRPC channel has been closed.
+    ChannelClosed,
+}
+
+impl StdError for RequestError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match *self {
+            RequestError::Conversion { ref inner } => Some(inner),
+            RequestError::Grpc { ref inner } => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for RequestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RequestError::Conversion { ref inner } => inner.fmt(f),
+            RequestError::UnexpectedEmptyResponse { request_id } => {
+                write!(f, "unexpected empty response for request ID {}", request_id)
+            }
+            RequestError::Grpc { ref inner } => write!(f, "grpc error: {}", inner),
+            RequestError::ChannelClosed => write!(f, "failed to send request: channel closed"),
+        }
+    }
+}
+
+impl From<ConversionError> for RequestError {
+    fn from(err: ConversionError) -> Self {
+        RequestError::Conversion { inner: err }
+    }
+}
+
+impl From<Status> for RequestError {
+    fn from(err: Status) -> Self {
+        RequestError::Grpc { inner: err }
+    }
+}
+
+impl<T> From<mpsc::error::SendError<T>> for RequestError {
+    fn from(_: mpsc::error::SendError<T>) -> Self {
+        RequestError::ChannelClosed
+    }
+}
+
+#[cfg(feature = "metrics")]
+impl RequestError {
+    /// Labels this error for the metrics `outcome` dimension.
+    fn metrics_outcome(&self) -> &'static str {
+        match self {
+            RequestError::Conversion { .. } => "conversion-error",
+            RequestError::Grpc { .. } | RequestError::UnexpectedEmptyResponse { .. } => "grpc-error",
+            RequestError::ChannelClosed => "transport-error",
+        }
+    }
+}
+
+/// The error returned if a client operation failed. This is a superset of
+/// both `TransportError` and `RequestError`, useful when code needs to
+/// handle both kinds of calls uniformly; prefer the narrower types when you
+/// only need to match on what a given call can actually produce.
 #[derive(Debug)]
 pub enum ClientError {
     /// Conversion between an IndraDB and its protobuf equivalent failed.
     Conversion { inner: ConversionError },
-    /// A gRPC stream response had an unexpected response ID, implying a bug.
-    UnexpectedResponseId { expected: u32, actual: u32 },
     /// A gRPC stream response had an unexpected empty body, implying a bug.
     UnexpectedEmptyResponse { request_id: u32 },
     /// A gRPC error.
@@ -53,9 +189,6 @@ impl fmt::Display for ClientError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ClientError::Conversion { ref inner } => inner.fmt(f),
-            ClientError::UnexpectedResponseId { expected, actual } => {
-                write!(f, "unexpected response ID; expected {}, got {}", expected, actual)
-            }
             ClientError::UnexpectedEmptyResponse { request_id } => {
                 write!(f, "unexpected empty response for request ID {}", request_id)
             }
@@ -90,6 +223,227 @@ impl<T> From<mpsc::error::SendError<T>> for ClientError {
     }
 }
 
+impl From<TransportError> for ClientError {
+    fn from(err: TransportError) -> Self {
+        match err {
+            TransportError::Grpc { inner } => ClientError::Grpc { inner },
+            TransportError::Transport { inner } => ClientError::Transport { inner },
+            TransportError::ChannelClosed => ClientError::ChannelClosed,
+        }
+    }
+}
+
+impl From<RequestError> for ClientError {
+    fn from(err: RequestError) -> Self {
+        match err {
+            RequestError::Conversion { inner } => ClientError::Conversion { inner },
+            RequestError::UnexpectedEmptyResponse { request_id } => ClientError::UnexpectedEmptyResponse { request_id },
+            RequestError::Grpc { inner } => ClientError::Grpc { inner },
+            RequestError::ChannelClosed => ClientError::ChannelClosed,
+        }
+    }
+}
+
+/// A policy controlling how `Client` retries a top-level operation after a
+/// transient failure.
+///
+/// The delay before the Nth retry (counting from zero) is `min(max_delay,
+/// initial_delay * backoff_multiplier^N)`, plus - if `jitter` is set - a
+/// random amount in `[0, delay)`, so that many clients reconnecting at once
+/// don't all retry in lockstep.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// The maximum number of times to attempt an operation, including the
+    /// first attempt. A value of `1` disables retries entirely.
+    pub max_attempts: u32,
+    /// The delay before the first retry.
+    pub initial_delay: std::time::Duration,
+    /// The factor the delay is multiplied by after each retry.
+    pub backoff_multiplier: f64,
+    /// The maximum backoff delay between retries, regardless of how many
+    /// attempts have been made. Jitter, if enabled, is added on top of it.
+    pub max_delay: std::time::Duration,
+    /// Whether to add a random amount in `[0, delay)` to each delay.
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    /// Retries are opt-in: by default, a single attempt is made and failures
+    /// are returned to the caller immediately.
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            initial_delay: std::time::Duration::from_millis(50),
+            backoff_multiplier: 2.0,
+            max_delay: std::time::Duration::from_secs(5),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Classifies whether an error is worth retrying. Transport-level
+    /// failures and a handful of gRPC status codes that indicate a
+    /// transient condition are retryable; everything else (bad arguments,
+    /// conversion bugs, protocol bugs) is terminal.
+    fn is_retryable(&self, err: &TransportError) -> bool {
+        match err {
+            TransportError::Transport { .. } | TransportError::ChannelClosed => true,
+            TransportError::Grpc { inner } => {
+                matches!(inner.code(), tonic::Code::Unavailable | tonic::Code::ResourceExhausted)
+            }
+        }
+    }
+
+    fn delay_for_attempt(&self, attempt: u32) -> std::time::Duration {
+        let backoff = self.initial_delay.as_secs_f64() * self.backoff_multiplier.powi(attempt as i32);
+        let capped = backoff.min(self.max_delay.as_secs_f64());
+        let delay = if self.jitter { capped + capped * jitter_fraction() } else { capped };
+        std::time::Duration::from_secs_f64(delay)
+    }
+}
+
+/// Returns a pseudo-random value in `[0, 1)`, used to jitter retry delays.
+/// This intentionally avoids pulling in a dedicated RNG crate for something
+/// this low-stakes.
+fn jitter_fraction() -> f64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().subsec_nanos() as u64;
+    let mut x = nanos ^ 0x9E37_79B9_7F4A_7C15;
+    x ^= x << 13;
+    x ^= x >> 7;
+    x ^= x << 17;
+    (x % 1_000_000) as f64 / 1_000_000.0
+}
+
+/// OpenTelemetry instrumentation for `Client`/`Transaction` operations,
+/// enabled via the `metrics` cargo feature and `Client::with_meter`.
+#[cfg(feature = "metrics")]
+mod otel {
+    use opentelemetry::metrics::{Counter, Histogram, Meter, UpDownCounter};
+    use opentelemetry::KeyValue;
+    use std::time::Instant;
+
+    pub(crate) struct ClientMetrics {
+        calls: Counter<u64>,
+        call_duration: Histogram<f64>,
+        calls_in_flight: UpDownCounter<i64>,
+        items_streamed: Counter<u64>,
+    }
+
+    impl ClientMetrics {
+        pub(crate) fn new(meter: &Meter) -> Self {
+            ClientMetrics {
+                calls: meter
+                    .u64_counter("indradb.client.calls")
+                    .with_description("Number of client operations, by operation and outcome")
+                    .init(),
+                call_duration: meter
+                    .f64_histogram("indradb.client.call_duration")
+                    .with_description("Client operation latency, by operation and outcome")
+                    .with_unit("s")
+                    .init(),
+                calls_in_flight: meter
+                    .i64_up_down_counter("indradb.client.calls_in_flight")
+                    .with_description("Number of client operations currently in flight, by operation")
+                    .init(),
+                items_streamed: meter
+                    .u64_counter("indradb.client.items_streamed")
+                    .with_description("Number of items returned by multi-response operations, by operation")
+                    .init(),
+            }
+        }
+
+        /// Marks the start of a call, returning the instant to pass to
+        /// `finish_call` once it completes.
+        pub(crate) fn start_call(&self, operation: &'static str) -> Instant {
+            self.calls_in_flight.add(1, &[KeyValue::new("operation", operation)]);
+            Instant::now()
+        }
+
+        /// Records a call's outcome and latency, and undoes the in-flight
+        /// increment `start_call` made.
+        pub(crate) fn finish_call(&self, operation: &'static str, outcome: &'static str, start: Instant) {
+            let attributes = [KeyValue::new("operation", operation), KeyValue::new("outcome", outcome)];
+            self.calls.add(1, &attributes);
+            self.call_duration.record(start.elapsed().as_secs_f64(), &attributes);
+            self.calls_in_flight.add(-1, &[KeyValue::new("operation", operation)]);
+        }
+
+        /// Records how many items a multi-response operation streamed back.
+        pub(crate) fn record_items_streamed(&self, operation: &'static str, count: u64) {
+            self.items_streamed.add(count, &[KeyValue::new("operation", operation)]);
+        }
+    }
+}
+
+/// Builds a `Client`, optionally configuring its retry behavior.
+pub struct ClientBuilder {
+    endpoint: Endpoint,
+    retry_policy: RetryPolicy,
+    channel_capacity: usize,
+}
+
+impl ClientBuilder {
+    /// Starts building a client for the given server endpoint.
+    pub fn new(endpoint: Endpoint) -> Self {
+        ClientBuilder {
+            endpoint,
+            retry_policy: RetryPolicy::default(),
+            channel_capacity: CHANNEL_CAPACITY,
+        }
+    }
+
+    /// Sets the retry policy used to recover from transient failures. See
+    /// `RetryPolicy` for what counts as transient.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets the capacity of the `mpsc` channel used to feed outgoing
+    /// requests for `bulk_insert` and `transaction` streams. A larger
+    /// capacity lets the feeder run further ahead of the server at the cost
+    /// of more buffered, unsent items; a smaller capacity applies more
+    /// backpressure to the caller. Defaults to `CHANNEL_CAPACITY`.
+    ///
+    /// Clamped to at least 1: `tokio::sync::mpsc::channel` panics on a `0`
+    /// buffer, and that panic would otherwise surface nowhere near this
+    /// call, on the first `bulk_insert`/`transaction` after `connect`.
+    pub fn channel_capacity(mut self, channel_capacity: usize) -> Self {
+        self.channel_capacity = channel_capacity.max(1);
+        self
+    }
+
+    /// Connects to the server, retrying according to the configured
+    /// `RetryPolicy` if the initial connection attempt fails transiently.
+    pub async fn connect(self) -> Result<Client, TransportError> {
+        let mut attempt = 0;
+        loop {
+            match crate::ProtoClient::connect(self.endpoint.clone()).await {
+                Ok(inner) => {
+                    return Ok(Client {
+                        inner,
+                        endpoint: self.endpoint,
+                        retry_policy: self.retry_policy,
+                        channel_capacity: self.channel_capacity,
+                        #[cfg(feature = "metrics")]
+                        metrics: None,
+                    });
+                }
+                Err(err) => {
+                    let err = TransportError::from(err);
+                    if attempt + 1 >= self.retry_policy.max_attempts || !self.retry_policy.is_retryable(&err) {
+                        return Err(err);
+                    }
+                    tokio::time::sleep(self.retry_policy.delay_for_attempt(attempt)).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+}
+
 /// A higher-level client implementation.
 ///
 /// This should be better suited than the low-level client auto-generated by
@@ -99,29 +453,136 @@ impl<T> From<mpsc::error::SendError<T>> for ClientError {
 /// IndraDB, but they cannot implement them directly since the functions here
 /// are async.
 #[derive(Clone)]
-pub struct Client(crate::ProtoClient<Channel>);
+pub struct Client {
+    inner: crate::ProtoClient<Channel>,
+    endpoint: Endpoint,
+    retry_policy: RetryPolicy,
+    channel_capacity: usize,
+    #[cfg(feature = "metrics")]
+    metrics: Option<Arc<otel::ClientMetrics>>,
+}
 
 impl Client {
-    /// Creates a new client.
+    /// Creates a new client with no retries configured. Use `ClientBuilder`
+    /// directly if you want transient failures to be retried.
     ///
     /// # Arguments
     /// * `endpoint`: The server endpoint.
-    pub async fn new(endpoint: Endpoint) -> Result<Self, ClientError> {
-        let client = crate::ProtoClient::connect(endpoint).await?;
-        Ok(Client { 0: client })
+    pub async fn new(endpoint: Endpoint) -> Result<Self, TransportError> {
+        ClientBuilder::new(endpoint).connect().await
     }
 
-    /// Pings the server.
-    pub async fn ping(&mut self) -> Result<(), ClientError> {
-        self.0.ping(()).await?;
+    /// Creates a new client that records OpenTelemetry metrics and emits
+    /// tracing spans for every operation: a per-operation/outcome call
+    /// counter, a latency histogram, an in-flight gauge, and - for
+    /// multi-response operations - a count of items streamed back.
+    ///
+    /// Requires the `metrics` feature.
+    #[cfg(feature = "metrics")]
+    pub async fn with_meter(endpoint: Endpoint, meter: Meter) -> Result<Self, TransportError> {
+        let mut client = ClientBuilder::new(endpoint).connect().await?;
+        client.metrics = Some(Arc::new(otel::ClientMetrics::new(&meter)));
+        Ok(client)
+    }
+
+    /// Reconnects to `self.endpoint`, replacing the current connection. Only
+    /// the handshake for a given top-level operation is ever replayed this
+    /// way - a transaction already in progress can't be transparently
+    /// resumed, since the server has no way to know which of its in-flight
+    /// responses the caller already saw.
+    ///
+    /// Makes exactly one connect attempt and never sleeps. Retrying a
+    /// transient failure here is the calling operation's retry loop's job:
+    /// it already tracks an `attempt` budget against `self.retry_policy`,
+    /// and a second independent retry loop in here would let a single
+    /// top-level call burn through up to `max_attempts` reconnects for
+    /// every one of its own `max_attempts` tries.
+    async fn reconnect(&mut self) -> Result<(), TransportError> {
+        self.inner = crate::ProtoClient::connect(self.endpoint.clone()).await?;
         Ok(())
     }
 
+    /// Pings the server.
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self)))]
+    pub async fn ping(&mut self) -> Result<(), TransportError> {
+        #[cfg(feature = "metrics")]
+        let call_start = self.metrics.as_ref().map(|m| m.start_call("ping"));
+
+        let mut attempt = 0;
+        loop {
+            match self.inner.ping(()).await {
+                Ok(_) => {
+                    #[cfg(feature = "metrics")]
+                    if let (Some(metrics), Some(start)) = (&self.metrics, call_start) {
+                        metrics.finish_call("ping", "ok", start);
+                    }
+                    return Ok(());
+                }
+                Err(status) => {
+                    let err = TransportError::from(status);
+                    if attempt + 1 >= self.retry_policy.max_attempts || !self.retry_policy.is_retryable(&err) {
+                        #[cfg(feature = "metrics")]
+                        if let (Some(metrics), Some(start)) = (&self.metrics, call_start) {
+                            metrics.finish_call("ping", err.metrics_outcome(), start);
+                        }
+                        return Err(err);
+                    }
+                    tokio::time::sleep(self.retry_policy.delay_for_attempt(attempt)).await;
+                    attempt += 1;
+                    if let Err(err) = self.reconnect().await {
+                        if attempt >= self.retry_policy.max_attempts || !self.retry_policy.is_retryable(&err) {
+                            #[cfg(feature = "metrics")]
+                            if let (Some(metrics), Some(start)) = (&self.metrics, call_start) {
+                                metrics.finish_call("ping", err.metrics_outcome(), start);
+                            }
+                            return Err(err);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     /// Syncs persisted content. Depending on the datastore implementation,
     /// this has different meanings - including potentially being a no-op.
-    pub async fn sync(&mut self) -> Result<(), ClientError> {
-        self.0.sync(()).await?;
-        Ok(())
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self)))]
+    pub async fn sync(&mut self) -> Result<(), TransportError> {
+        #[cfg(feature = "metrics")]
+        let call_start = self.metrics.as_ref().map(|m| m.start_call("sync"));
+
+        let mut attempt = 0;
+        loop {
+            match self.inner.sync(()).await {
+                Ok(_) => {
+                    #[cfg(feature = "metrics")]
+                    if let (Some(metrics), Some(start)) = (&self.metrics, call_start) {
+                        metrics.finish_call("sync", "ok", start);
+                    }
+                    return Ok(());
+                }
+                Err(status) => {
+                    let err = TransportError::from(status);
+                    if attempt + 1 >= self.retry_policy.max_attempts || !self.retry_policy.is_retryable(&err) {
+                        #[cfg(feature = "metrics")]
+                        if let (Some(metrics), Some(start)) = (&self.metrics, call_start) {
+                            metrics.finish_call("sync", err.metrics_outcome(), start);
+                        }
+                        return Err(err);
+                    }
+                    tokio::time::sleep(self.retry_policy.delay_for_attempt(attempt)).await;
+                    attempt += 1;
+                    if let Err(err) = self.reconnect().await {
+                        if attempt >= self.retry_policy.max_attempts || !self.retry_policy.is_retryable(&err) {
+                            #[cfg(feature = "metrics")]
+                            if let (Some(metrics), Some(start)) = (&self.metrics, call_start) {
+                                metrics.finish_call("sync", err.metrics_outcome(), start);
+                            }
+                            return Err(err);
+                        }
+                    }
+                }
+            }
+        }
     }
 
     /// Bulk inserts many vertices, edges, and/or properties.
@@ -137,118 +598,687 @@ impl Client {
     ///
     /// # Arguments
     /// * `items`: The items to insert.
-    pub async fn bulk_insert<I>(&mut self, items: I) -> Result<(), ClientError>
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, items)))]
+    pub async fn bulk_insert<I>(&mut self, items: I) -> Result<(), TransportError>
     where
         I: Iterator<Item = indradb::BulkInsertItem>,
     {
         let items: Vec<indradb::BulkInsertItem> = items.collect();
-        let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
-        tokio::spawn(async move {
-            for item in items.into_iter() {
-                if tx.send(item.into()).await.is_err() {
-                    return;
+
+        #[cfg(feature = "metrics")]
+        let call_start = self.metrics.as_ref().map(|m| m.start_call("bulk_insert"));
+
+        let mut attempt = 0;
+        loop {
+            let (tx, rx) = mpsc::channel(self.channel_capacity);
+            let batch = items.clone();
+            let feeder = tokio::spawn(async move {
+                for item in batch.into_iter() {
+                    tx.send(item.into()).await?;
+                }
+                Ok::<(), mpsc::error::SendError<crate::BulkInsertItem>>(())
+            });
+
+            let bulk_insert_result = self.inner.bulk_insert(Request::new(ReceiverStream::new(rx))).await;
+            let fed_everything = matches!(feeder.await, Ok(Ok(()))); // JoinError or closed channel both count as truncated
+
+            match bulk_insert_result {
+                Ok(_) if fed_everything => {
+                    #[cfg(feature = "metrics")]
+                    if let (Some(metrics), Some(start)) = (&self.metrics, call_start) {
+                        metrics.finish_call("bulk_insert", "ok", start);
+                    }
+                    return Ok(());
+                }
+                Ok(_) => {
+                    // The server accepted the call, but the feeder couldn't
+                    // stream every item to it - the insert was truncated.
+                    #[cfg(feature = "metrics")]
+                    if let (Some(metrics), Some(start)) = (&self.metrics, call_start) {
+                        metrics.finish_call("bulk_insert", TransportError::ChannelClosed.metrics_outcome(), start);
+                    }
+                    return Err(TransportError::ChannelClosed);
+                }
+                Err(status) => {
+                    let err = TransportError::from(status);
+                    if attempt + 1 >= self.retry_policy.max_attempts || !self.retry_policy.is_retryable(&err) {
+                        #[cfg(feature = "metrics")]
+                        if let (Some(metrics), Some(start)) = (&self.metrics, call_start) {
+                            metrics.finish_call("bulk_insert", err.metrics_outcome(), start);
+                        }
+                        return Err(err);
+                    }
+                    tokio::time::sleep(self.retry_policy.delay_for_attempt(attempt)).await;
+                    attempt += 1;
+                    if let Err(err) = self.reconnect().await {
+                        if attempt >= self.retry_policy.max_attempts || !self.retry_policy.is_retryable(&err) {
+                            #[cfg(feature = "metrics")]
+                            if let (Some(metrics), Some(start)) = (&self.metrics, call_start) {
+                                metrics.finish_call("bulk_insert", err.metrics_outcome(), start);
+                            }
+                            return Err(err);
+                        }
+                    }
                 }
             }
+        }
+    }
+
+    /// Bulk inserts items from a `Stream` rather than an `Iterator`, so a
+    /// very large ingest never has to be collected into a `Vec` up front -
+    /// the feeder only ever buffers up to `channel_capacity` items ahead of
+    /// the server, giving proper end-to-end backpressure.
+    ///
+    /// Unlike `bulk_insert`, this isn't covered by the client's retry
+    /// policy: a `Stream` generally can't be replayed from the start the
+    /// way a cloned `Vec` can, so a transient failure here is returned to
+    /// the caller directly instead of being retried.
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, items)))]
+    pub async fn bulk_insert_stream<S>(&mut self, items: S) -> Result<(), TransportError>
+    where
+        S: Stream<Item = indradb::BulkInsertItem> + Send + 'static,
+    {
+        #[cfg(feature = "metrics")]
+        let call_start = self.metrics.as_ref().map(|m| m.start_call("bulk_insert_stream"));
+
+        let (tx, rx) = mpsc::channel(self.channel_capacity);
+        let feeder = tokio::spawn(async move {
+            tokio::pin!(items);
+            while let Some(item) = items.next().await {
+                tx.send(item.into()).await?;
+            }
+            Ok::<(), mpsc::error::SendError<crate::BulkInsertItem>>(())
         });
 
-        self.0.bulk_insert(Request::new(ReceiverStream::new(rx))).await?;
-        Ok(())
+        let bulk_insert_result = self.inner.bulk_insert(Request::new(ReceiverStream::new(rx))).await;
+        let fed_everything = matches!(feeder.await, Ok(Ok(())));
+
+        let result = match bulk_insert_result {
+            Ok(_) if fed_everything => Ok(()),
+            Ok(_) => Err(TransportError::ChannelClosed),
+            Err(status) => Err(TransportError::from(status)),
+        };
+
+        #[cfg(feature = "metrics")]
+        if let (Some(metrics), Some(start)) = (&self.metrics, call_start) {
+            let outcome = match &result {
+                Ok(_) => "ok",
+                Err(err) => err.metrics_outcome(),
+            };
+            metrics.finish_call("bulk_insert_stream", outcome, start);
+        }
+
+        result
     }
 
-    /// Creates a new transaction.
-    pub async fn transaction(&mut self) -> Result<Transaction, ClientError> {
-        let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
-        let response = self.0.transaction(Request::new(ReceiverStream::new(rx))).await?;
-        Ok(Transaction::new(tx, response.into_inner()))
+    /// Creates a new transaction. If establishing the transaction's
+    /// bidirectional stream fails transiently, the connect-and-open
+    /// handshake is retried; once the transaction is open, calls made on it
+    /// are not covered by this client's retry policy.
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self)))]
+    pub async fn transaction(&mut self) -> Result<Transaction, TransportError> {
+        #[cfg(feature = "metrics")]
+        let call_start = self.metrics.as_ref().map(|m| m.start_call("transaction"));
+
+        let mut attempt = 0;
+        loop {
+            let (tx, rx) = mpsc::channel(self.channel_capacity);
+            match self.inner.transaction(Request::new(ReceiverStream::new(rx))).await {
+                Ok(response) => {
+                    #[cfg(feature = "metrics")]
+                    if let (Some(metrics), Some(start)) = (&self.metrics, call_start) {
+                        metrics.finish_call("transaction", "ok", start);
+                    }
+                    #[cfg(feature = "metrics")]
+                    let transaction = Transaction::new_with_metrics(tx, response.into_inner(), self.metrics.clone());
+                    #[cfg(not(feature = "metrics"))]
+                    let transaction = Transaction::new(tx, response.into_inner());
+                    return Ok(transaction);
+                }
+                Err(status) => {
+                    let err = TransportError::from(status);
+                    if attempt + 1 >= self.retry_policy.max_attempts || !self.retry_policy.is_retryable(&err) {
+                        #[cfg(feature = "metrics")]
+                        if let (Some(metrics), Some(start)) = (&self.metrics, call_start) {
+                            metrics.finish_call("transaction", err.metrics_outcome(), start);
+                        }
+                        return Err(err);
+                    }
+                    tokio::time::sleep(self.retry_policy.delay_for_attempt(attempt)).await;
+                    attempt += 1;
+                    if let Err(err) = self.reconnect().await {
+                        if attempt >= self.retry_policy.max_attempts || !self.retry_policy.is_retryable(&err) {
+                            #[cfg(feature = "metrics")]
+                            if let (Some(metrics), Some(start)) = (&self.metrics, call_start) {
+                                metrics.finish_call("transaction", err.metrics_outcome(), start);
+                            }
+                            return Err(err);
+                        }
+                    }
+                }
+            }
+        }
     }
 
-    pub async fn index_property<T: Into<indradb::Identifier>>(&mut self, name: T) -> Result<(), ClientError> {
-        self.0
-            .index_property(Request::new(crate::IndexPropertyRequest {
-                name: Some(name.into().into()),
-            }))
-            .await?;
-        Ok(())
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self)))]
+    pub async fn index_property<T: Into<indradb::Identifier> + Clone>(&mut self, name: T) -> Result<(), TransportError> {
+        #[cfg(feature = "metrics")]
+        let call_start = self.metrics.as_ref().map(|m| m.start_call("index_property"));
+
+        let mut attempt = 0;
+        loop {
+            let request = Request::new(crate::IndexPropertyRequest {
+                name: Some(name.clone().into().into()),
+            });
+            match self.inner.index_property(request).await {
+                Ok(_) => {
+                    #[cfg(feature = "metrics")]
+                    if let (Some(metrics), Some(start)) = (&self.metrics, call_start) {
+                        metrics.finish_call("index_property", "ok", start);
+                    }
+                    return Ok(());
+                }
+                Err(status) => {
+                    let err = TransportError::from(status);
+                    if attempt + 1 >= self.retry_policy.max_attempts || !self.retry_policy.is_retryable(&err) {
+                        #[cfg(feature = "metrics")]
+                        if let (Some(metrics), Some(start)) = (&self.metrics, call_start) {
+                            metrics.finish_call("index_property", err.metrics_outcome(), start);
+                        }
+                        return Err(err);
+                    }
+                    tokio::time::sleep(self.retry_policy.delay_for_attempt(attempt)).await;
+                    attempt += 1;
+                    if let Err(err) = self.reconnect().await {
+                        if attempt >= self.retry_policy.max_attempts || !self.retry_policy.is_retryable(&err) {
+                            #[cfg(feature = "metrics")]
+                            if let (Some(metrics), Some(start)) = (&self.metrics, call_start) {
+                                metrics.finish_call("index_property", err.metrics_outcome(), start);
+                            }
+                            return Err(err);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+type ResponseResult = Result<crate::TransactionResponseVariant, RequestError>;
+
+/// The sink a pending request's response(s) get routed to once the
+/// background demultiplexer task reads them off the stream.
+enum PendingResponder {
+    /// A request that expects exactly one response.
+    Single(oneshot::Sender<ResponseResult>),
+    /// A request that expects zero or more responses, terminated by an
+    /// `Empty` sentinel.
+    Multi(mpsc::UnboundedSender<ResponseResult>),
+}
+
+/// The reason the background demultiplexer task stopped reading the
+/// response stream for good. Kept separate from `RequestError` (rather than
+/// reusing it directly) because it needs to be `Clone`: every call made
+/// after the transaction has died gets its own copy of the same terminal
+/// error, and `RequestError::Conversion` - a variant `fail_all` never
+/// produces - isn't `Clone`.
+#[derive(Clone)]
+enum DemuxDeadReason {
+    ChannelClosed,
+    Grpc { inner: Status },
+}
+
+impl From<DemuxDeadReason> for RequestError {
+    fn from(reason: DemuxDeadReason) -> Self {
+        match reason {
+            DemuxDeadReason::ChannelClosed => RequestError::ChannelClosed,
+            DemuxDeadReason::Grpc { inner } => RequestError::Grpc { inner },
+        }
+    }
+}
+
+/// `Demultiplexer`'s internal state, guarded by a single lock so that
+/// "is the transaction dead" and "register a new pending request" are
+/// checked atomically - without that, a register racing the background
+/// reader's shutdown could insert a new entry after `fail_all` has already
+/// drained `pending` for the last time, leaking a responder nothing will
+/// ever signal.
+#[derive(Default)]
+struct DemuxState {
+    pending: HashMap<u32, PendingResponder>,
+    /// Set once the background reader hits the end of the response stream
+    /// (or an error on it); it will never call `dispatch` again past that
+    /// point.
+    dead: Option<DemuxDeadReason>,
+}
+
+/// Routes out-of-order `TransactionResponse`s back to the caller that sent
+/// the matching request, keyed by `request_id`.
+#[derive(Default)]
+struct Demultiplexer {
+    state: Mutex<DemuxState>,
+}
+
+impl Demultiplexer {
+    async fn register_single(&self, request_id: u32) -> Result<oneshot::Receiver<ResponseResult>, RequestError> {
+        let mut state = self.state.lock().await;
+        if let Some(reason) = state.dead.clone() {
+            return Err(reason.into());
+        }
+        let (tx, rx) = oneshot::channel();
+        state.pending.insert(request_id, PendingResponder::Single(tx));
+        Ok(rx)
+    }
+
+    async fn register_multi(&self, request_id: u32) -> Result<mpsc::UnboundedReceiver<ResponseResult>, RequestError> {
+        let mut state = self.state.lock().await;
+        if let Some(reason) = state.dead.clone() {
+            return Err(reason.into());
+        }
+        let (tx, rx) = mpsc::unbounded_channel();
+        state.pending.insert(request_id, PendingResponder::Multi(tx));
+        Ok(rx)
+    }
+
+    /// Routes a response to whichever caller is waiting on `request_id`. A
+    /// `Multi` responder is torn down once an `Empty` sentinel arrives or an
+    /// `Err` is routed to it - both terminate the stream, so there's nothing
+    /// left to dispatch future responses to and the entry would otherwise
+    /// leak for the life of the transaction.
+    async fn dispatch(&self, request_id: u32, response: ResponseResult) {
+        let is_empty = matches!(response.as_ref(), Ok(crate::TransactionResponseVariant::Empty(_)));
+        let is_err = response.is_err();
+        let mut state = self.state.lock().await;
+        let pending = &mut state.pending;
+        match pending.get(&request_id) {
+            Some(PendingResponder::Single(_)) => {
+                if let Some(PendingResponder::Single(tx)) = pending.remove(&request_id) {
+                    let _ = tx.send(response);
+                }
+            }
+            Some(PendingResponder::Multi(_)) if is_empty => {
+                pending.remove(&request_id);
+            }
+            Some(PendingResponder::Multi(_)) if is_err => {
+                if let Some(PendingResponder::Multi(tx)) = pending.remove(&request_id) {
+                    let _ = tx.send(response);
+                }
+            }
+            Some(PendingResponder::Multi(tx)) => {
+                let _ = tx.send(response);
+            }
+            // A response for an ID nobody's waiting on anymore (or that was
+            // never registered) implies a protocol bug; there's nobody left
+            // to hand it to, so it's dropped.
+            None => {}
+        }
+    }
+
+    /// Fails every still-pending request, e.g. because the response stream
+    /// ended or errored out, and marks the demultiplexer dead so every
+    /// later `register_single`/`register_multi` fails fast instead of
+    /// registering a responder the (now finished) background task will
+    /// never drive.
+    async fn fail_all(&self, reason: DemuxDeadReason) {
+        let mut state = self.state.lock().await;
+        state.dead = Some(reason.clone());
+        for (_, responder) in state.pending.drain() {
+            let err: RequestError = reason.clone().into();
+            match responder {
+                PendingResponder::Single(tx) => {
+                    let _ = tx.send(Err(err));
+                }
+                PendingResponder::Multi(tx) => {
+                    // Send a terminal `Err`, not just drop the sender: a bare
+                    // channel close looks identical to an `Empty` sentinel to
+                    // `request_multi`/the streaming variants, which would
+                    // otherwise read it as a normal end-of-stream and return
+                    // `Ok` with a silently truncated result set.
+                    let _ = tx.send(Err(err));
+                }
+            }
+        }
+    }
+}
+
+/// Drains `receiver` in the background and routes each response to the
+/// caller that's waiting on its `request_id`, so requests and responses no
+/// longer need to stay in lockstep.
+fn spawn_demultiplexer(mut receiver: Streaming<crate::TransactionResponse>, demux: Arc<Demultiplexer>) {
+    tokio::spawn(async move {
+        loop {
+            match receiver.message().await {
+                Ok(Some(crate::TransactionResponse {
+                    request_id,
+                    response: Some(response),
+                })) => {
+                    demux.dispatch(request_id, Ok(response)).await;
+                }
+                Ok(Some(crate::TransactionResponse { request_id, response: None })) => {
+                    demux
+                        .dispatch(request_id, Err(RequestError::UnexpectedEmptyResponse { request_id }))
+                        .await;
+                }
+                Ok(None) => {
+                    demux.fail_all(DemuxDeadReason::ChannelClosed).await;
+                    break;
+                }
+                Err(status) => {
+                    demux
+                        .fail_all(DemuxDeadReason::Grpc {
+                            inner: Status::new(status.code(), status.message()),
+                        })
+                        .await;
+                    break;
+                }
+            }
+        }
+    });
+}
+
+/// Names a `TransactionRequestVariant` for metrics/tracing purposes,
+/// matching the name of the `Transaction` method that produces it.
+#[cfg(feature = "metrics")]
+fn request_variant_name(variant: &crate::TransactionRequestVariant) -> &'static str {
+    use crate::TransactionRequestVariant::*;
+    match variant {
+        CreateVertex(_) => "create_vertex",
+        CreateVertexFromType(_) => "create_vertex_from_type",
+        GetVertices(_) => "get_vertices",
+        DeleteVertices(_) => "delete_vertices",
+        GetVertexCount(_) => "get_vertex_count",
+        CreateEdge(_) => "create_edge",
+        GetEdges(_) => "get_edges",
+        DeleteEdges(_) => "delete_edges",
+        GetEdgeCount(_) => "get_edge_count",
+        GetVertexProperties(_) => "get_vertex_properties",
+        GetAllVertexProperties(_) => "get_all_vertex_properties",
+        SetVertexProperties(_) => "set_vertex_properties",
+        DeleteVertexProperties(_) => "delete_vertex_properties",
+        GetEdgeProperties(_) => "get_edge_properties",
+        GetAllEdgeProperties(_) => "get_all_edge_properties",
+        SetEdgeProperties(_) => "set_edge_properties",
+        DeleteEdgeProperties(_) => "delete_edge_properties",
+    }
+}
+
+/// Labels a `Transaction`/`Pipeline` request's outcome for metrics purposes.
+#[cfg(feature = "metrics")]
+fn request_outcome<T>(result: &Result<T, RequestError>) -> &'static str {
+    match result {
+        Ok(_) => "ok",
+        Err(err) => err.metrics_outcome(),
+    }
+}
+
+/// Wraps a multi-response stream so the call counter/latency/in-flight
+/// metrics and the "items streamed" counter get recorded once the stream is
+/// fully drained (or fails partway through), the same way `request_multi`
+/// records them once its `Vec` is fully collected.
+#[cfg(feature = "metrics")]
+struct InstrumentedStream<T> {
+    inner: Pin<Box<dyn Stream<Item = Result<T, RequestError>> + Send>>,
+    metrics: Option<Arc<otel::ClientMetrics>>,
+    op: &'static str,
+    start: Option<std::time::Instant>,
+    count: u64,
+    finished: bool,
+}
+
+#[cfg(feature = "metrics")]
+impl<T> InstrumentedStream<T> {
+    fn finish(&mut self, outcome: &'static str) {
+        if self.finished {
+            return;
+        }
+        self.finished = true;
+        if let (Some(metrics), Some(start)) = (&self.metrics, self.start) {
+            metrics.finish_call(self.op, outcome, start);
+            metrics.record_items_streamed(self.op, self.count);
+        }
+    }
+}
+
+/// Drops can happen before a stream is fully drained - the caller might
+/// `.take(n)` it, `break` out of a loop early, or bail out on the first
+/// error without polling to exhaustion. Without this, `finish` would never
+/// run for a partially-consumed stream and the in-flight gauge `start_call`
+/// incremented would leak permanently.
+#[cfg(feature = "metrics")]
+impl<T> Drop for InstrumentedStream<T> {
+    fn drop(&mut self) {
+        self.finish("cancelled");
+    }
+}
+
+#[cfg(feature = "metrics")]
+impl<T> Stream for InstrumentedStream<T> {
+    type Item = Result<T, RequestError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> std::task::Poll<Option<Self::Item>> {
+        let this = self.as_mut().get_mut();
+        match this.inner.as_mut().poll_next(cx) {
+            std::task::Poll::Ready(Some(Ok(item))) => {
+                this.count += 1;
+                std::task::Poll::Ready(Some(Ok(item)))
+            }
+            std::task::Poll::Ready(Some(Err(err))) => {
+                this.finish(err.metrics_outcome());
+                std::task::Poll::Ready(Some(Err(err)))
+            }
+            std::task::Poll::Ready(None) => {
+                this.finish("ok");
+                std::task::Poll::Ready(None)
+            }
+            std::task::Poll::Pending => std::task::Poll::Pending,
+        }
+    }
+}
+
+/// Wraps `inner` with `InstrumentedStream` bookkeeping, starting the call
+/// clock now and recording its outcome once the stream is drained.
+#[cfg(feature = "metrics")]
+fn instrument_stream<T: Send + 'static>(
+    inner: impl Stream<Item = Result<T, RequestError>> + Send + 'static,
+    metrics: Option<Arc<otel::ClientMetrics>>,
+    op: &'static str,
+    start: Option<std::time::Instant>,
+) -> impl Stream<Item = Result<T, RequestError>> {
+    InstrumentedStream {
+        inner: Box::pin(inner),
+        metrics,
+        op,
+        start,
+        count: 0,
+        finished: false,
+    }
+}
+
+/// Tracks a single `Pipeline` call's in-flight gauge from the moment it's
+/// enqueued to the moment its queued future actually runs (inside
+/// `Pipeline::execute`) and records the outcome exactly once, the same way
+/// `InstrumentedStream` does for streams.
+#[cfg(feature = "metrics")]
+struct PipelineCallMetrics {
+    metrics: Option<Arc<otel::ClientMetrics>>,
+    op: &'static str,
+    start: Option<std::time::Instant>,
+    finished: bool,
+}
+
+#[cfg(feature = "metrics")]
+impl PipelineCallMetrics {
+    fn start(metrics: Option<Arc<otel::ClientMetrics>>, op: &'static str) -> Self {
+        let start = metrics.as_ref().map(|m| m.start_call(op));
+        PipelineCallMetrics {
+            metrics,
+            op,
+            start,
+            finished: false,
+        }
+    }
+
+    fn finish(&mut self, outcome: &'static str) {
+        if self.finished {
+            return;
+        }
+        self.finished = true;
+        if let (Some(metrics), Some(start)) = (&self.metrics, self.start) {
+            metrics.finish_call(self.op, outcome, start);
+        }
+    }
+}
+
+/// A queued op's request can fail before its future is ever pushed onto
+/// `Pipeline::futures` (the `?` on `send_single`/`send_multi`), and a
+/// `Pipeline` can be dropped with futures still queued without `execute`
+/// ever running them - both would otherwise leak the in-flight increment
+/// `start` made.
+#[cfg(feature = "metrics")]
+impl Drop for PipelineCallMetrics {
+    fn drop(&mut self) {
+        self.finish("cancelled");
     }
 }
 
 /// A transaction.
 pub struct Transaction {
     sender: mpsc::Sender<crate::TransactionRequest>,
-    receiver: Streaming<crate::TransactionResponse>,
-    next_request_id: u32,
+    next_request_id: AtomicU32,
+    demux: Arc<Demultiplexer>,
+    #[cfg(feature = "metrics")]
+    metrics: Option<Arc<otel::ClientMetrics>>,
 }
 
 impl Transaction {
     fn new(sender: mpsc::Sender<crate::TransactionRequest>, receiver: Streaming<crate::TransactionResponse>) -> Self {
+        let demux = Arc::new(Demultiplexer::default());
+        spawn_demultiplexer(receiver, demux.clone());
         Transaction {
             sender,
-            receiver,
-            next_request_id: 0,
+            next_request_id: AtomicU32::new(0),
+            demux,
+            #[cfg(feature = "metrics")]
+            metrics: None,
         }
     }
 
-    async fn request(&mut self, request: crate::TransactionRequestVariant) -> Result<u32, ClientError> {
-        let request_id = self.next_request_id;
-        self.next_request_id += 1;
+    /// Like `new`, but carries over the `Client`'s OpenTelemetry metrics so
+    /// operations on this transaction are instrumented too.
+    #[cfg(feature = "metrics")]
+    fn new_with_metrics(
+        sender: mpsc::Sender<crate::TransactionRequest>,
+        receiver: Streaming<crate::TransactionResponse>,
+        metrics: Option<Arc<otel::ClientMetrics>>,
+    ) -> Self {
+        let mut transaction = Self::new(sender, receiver);
+        transaction.metrics = metrics;
+        transaction
+    }
 
+    /// Sends a request that expects a single response, returning as soon as
+    /// the request has been flushed rather than waiting on the response.
+    async fn send_single(
+        &self,
+        request: crate::TransactionRequestVariant,
+    ) -> Result<oneshot::Receiver<ResponseResult>, RequestError> {
+        let request_id = self.next_request_id.fetch_add(1, Ordering::SeqCst);
+        let rx = self.demux.register_single(request_id).await?;
         self.sender
             .send(crate::TransactionRequest {
                 request_id,
                 request: Some(request),
             })
             .await?;
+        Ok(rx)
+    }
 
-        Ok(request_id)
+    /// Sends a request that expects zero or more responses, returning as
+    /// soon as the request has been flushed rather than waiting on any
+    /// response.
+    async fn send_multi(
+        &self,
+        request: crate::TransactionRequestVariant,
+    ) -> Result<mpsc::UnboundedReceiver<ResponseResult>, RequestError> {
+        let request_id = self.next_request_id.fetch_add(1, Ordering::SeqCst);
+        let rx = self.demux.register_multi(request_id).await?;
+        self.sender
+            .send(crate::TransactionRequest {
+                request_id,
+                request: Some(request),
+            })
+            .await?;
+        Ok(rx)
     }
 
     async fn request_single(
-        &mut self,
+        &self,
         request: crate::TransactionRequestVariant,
-    ) -> Result<crate::TransactionResponseVariant, ClientError> {
-        let expected_request_id = self.request(request).await?;
-        match self.receiver.message().await? {
-            Some(crate::TransactionResponse {
-                request_id,
-                response: Some(response),
-            }) => {
-                check_request_id(expected_request_id, request_id)?;
-                Ok(response)
-            }
-            _ => Err(ClientError::UnexpectedEmptyResponse {
-                request_id: expected_request_id,
-            }),
+    ) -> Result<crate::TransactionResponseVariant, RequestError> {
+        #[cfg(feature = "metrics")]
+        let (op, call_start) = {
+            let op = request_variant_name(&request);
+            (op, self.metrics.as_ref().map(|m| m.start_call(op)))
+        };
+
+        let result = async move {
+            let rx = self.send_single(request).await?;
+            rx.await.map_err(|_| RequestError::ChannelClosed)?
+        }
+        .await;
+
+        #[cfg(feature = "metrics")]
+        if let (Some(metrics), Some(start)) = (&self.metrics, call_start) {
+            metrics.finish_call(op, request_outcome(&result), start);
         }
+
+        result
     }
 
     async fn request_multi(
-        &mut self,
+        &self,
         request: crate::TransactionRequestVariant,
-    ) -> Result<Vec<crate::TransactionResponseVariant>, ClientError> {
-        let expected_request_id = self.request(request).await?;
-        let mut values = Vec::default();
-        loop {
-            match self.receiver.message().await? {
-                Some(crate::TransactionResponse {
-                    request_id,
-                    response: Some(response),
-                }) => {
-                    check_request_id(expected_request_id, request_id)?;
+    ) -> Result<Vec<crate::TransactionResponseVariant>, RequestError> {
+        #[cfg(feature = "metrics")]
+        let (op, call_start) = {
+            let op = request_variant_name(&request);
+            (op, self.metrics.as_ref().map(|m| m.start_call(op)))
+        };
 
-                    if let crate::TransactionResponseVariant::Empty(_) = response {
-                        break;
-                    } else {
-                        values.push(response);
-                    }
-                }
-                _ => {
-                    return Err(ClientError::UnexpectedEmptyResponse {
-                        request_id: expected_request_id,
-                    });
-                }
+        let result = async move {
+            let mut rx = self.send_multi(request).await?;
+            let mut values = Vec::default();
+            while let Some(response) = rx.recv().await {
+                values.push(response?);
             }
+            Ok(values)
+        }
+        .await;
+
+        #[cfg(feature = "metrics")]
+        if let (Some(metrics), Some(start)) = (&self.metrics, call_start) {
+            metrics.finish_call(op, request_outcome(&result), start);
+            if let Ok(ref values) = result {
+                metrics.record_items_streamed(op, values.len() as u64);
+            }
+        }
+
+        result
+    }
+
+    /// Starts a pipeline that lets you enqueue several operations without
+    /// waiting on each response in turn.
+    ///
+    /// Every builder method sends its request as soon as it's called, so by
+    /// the time you call `execute`, some or all of the responses may
+    /// already be in flight back from the server - unlike calling the
+    /// equivalent `Transaction` methods one at a time, which pays a full
+    /// round-trip per operation.
+    pub fn pipeline(&self) -> Pipeline<'_> {
+        Pipeline {
+            transaction: self,
+            futures: Vec::new(),
         }
-        Ok(values)
     }
 
     /// Creates a new vertex. Returns whether the vertex was successfully
@@ -257,7 +1287,8 @@ impl Transaction {
     ///
     /// # Arguments
     /// * `vertex`: The vertex to create.
-    pub async fn create_vertex(&mut self, vertex: &indradb::Vertex) -> Result<bool, ClientError> {
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, vertex)))]
+    pub async fn create_vertex(&self, vertex: &indradb::Vertex) -> Result<bool, RequestError> {
         let request = crate::TransactionRequestVariant::CreateVertex(vertex.clone().into());
         Ok(self.request_single(request).await?.try_into()?)
     }
@@ -268,7 +1299,8 @@ impl Transaction {
     ///
     /// # Arguments
     /// * `t`: The type of the vertex to create.
-    pub async fn create_vertex_from_type(&mut self, t: indradb::Identifier) -> Result<Uuid, ClientError> {
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, t)))]
+    pub async fn create_vertex_from_type(&self, t: indradb::Identifier) -> Result<Uuid, RequestError> {
         let request = crate::TransactionRequestVariant::CreateVertexFromType(t.into());
         Ok(self.request_single(request).await?.try_into()?)
     }
@@ -277,10 +1309,8 @@ impl Transaction {
     ///
     /// # Arguments
     /// * `q`: The query to run.
-    pub async fn get_vertices<Q: Into<indradb::VertexQuery>>(
-        &mut self,
-        q: Q,
-    ) -> Result<Vec<indradb::Vertex>, ClientError> {
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, q)))]
+    pub async fn get_vertices<Q: Into<indradb::VertexQuery>>(&self, q: Q) -> Result<Vec<indradb::Vertex>, RequestError> {
         let request = crate::TransactionRequestVariant::GetVertices(q.into().into());
         let result: Result<Vec<indradb::Vertex>, ConversionError> = self
             .request_multi(request)
@@ -291,17 +1321,42 @@ impl Transaction {
         Ok(result?)
     }
 
+    /// Gets a range of vertices specified by a query, yielding each one as
+    /// it arrives instead of buffering the whole result set into a `Vec`.
+    ///
+    /// # Arguments
+    /// * `q`: The query to run.
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, q)))]
+    pub async fn get_vertices_stream<Q: Into<indradb::VertexQuery>>(
+        &self,
+        q: Q,
+    ) -> Result<impl Stream<Item = Result<indradb::Vertex, RequestError>>, RequestError> {
+        let request = crate::TransactionRequestVariant::GetVertices(q.into().into());
+        #[cfg(feature = "metrics")]
+        let (op, call_start) = {
+            let op = request_variant_name(&request);
+            (op, self.metrics.as_ref().map(|m| m.start_call(op)))
+        };
+        let rx = self.send_multi(request).await?;
+        let stream = UnboundedReceiverStream::new(rx).map(|response| Ok(response?.try_into()?));
+        #[cfg(feature = "metrics")]
+        let stream = instrument_stream(stream, self.metrics.clone(), op, call_start);
+        Ok(stream)
+    }
+
     /// Deletes existing vertices specified by a query.
     ///
     /// # Arguments
     /// * `q`: The query to run.
-    pub async fn delete_vertices<Q: Into<indradb::VertexQuery>>(&mut self, q: Q) -> Result<(), ClientError> {
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, q)))]
+    pub async fn delete_vertices<Q: Into<indradb::VertexQuery>>(&self, q: Q) -> Result<(), RequestError> {
         let request = crate::TransactionRequestVariant::DeleteVertices(q.into().into());
         Ok(self.request_single(request).await?.try_into()?)
     }
 
     /// Gets the number of vertices in the datastore.
-    pub async fn get_vertex_count(&mut self) -> Result<u64, ClientError> {
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self)))]
+    pub async fn get_vertex_count(&self) -> Result<u64, RequestError> {
         let request = crate::TransactionRequestVariant::GetVertexCount(());
         Ok(self.request_single(request).await?.try_into()?)
     }
@@ -313,7 +1368,8 @@ impl Transaction {
     ///
     /// # Arguments
     /// * `key`: The edge to create.
-    pub async fn create_edge(&mut self, key: &indradb::EdgeKey) -> Result<bool, ClientError> {
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, key)))]
+    pub async fn create_edge(&self, key: &indradb::EdgeKey) -> Result<bool, RequestError> {
         let request = crate::TransactionRequestVariant::CreateEdge(key.clone().into());
         Ok(self.request_single(request).await?.try_into()?)
     }
@@ -322,7 +1378,8 @@ impl Transaction {
     ///
     /// # Arguments
     /// * `q`: The query to run.
-    pub async fn get_edges<Q: Into<indradb::EdgeQuery>>(&mut self, q: Q) -> Result<Vec<indradb::Edge>, ClientError> {
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, q)))]
+    pub async fn get_edges<Q: Into<indradb::EdgeQuery>>(&self, q: Q) -> Result<Vec<indradb::Edge>, RequestError> {
         let request = crate::TransactionRequestVariant::GetEdges(q.into().into());
         let result: Result<Vec<indradb::Edge>, ConversionError> = self
             .request_multi(request)
@@ -333,11 +1390,35 @@ impl Transaction {
         Ok(result?)
     }
 
+    /// Gets a range of edges specified by a query, yielding each one as it
+    /// arrives instead of buffering the whole result set into a `Vec`.
+    ///
+    /// # Arguments
+    /// * `q`: The query to run.
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, q)))]
+    pub async fn get_edges_stream<Q: Into<indradb::EdgeQuery>>(
+        &self,
+        q: Q,
+    ) -> Result<impl Stream<Item = Result<indradb::Edge, RequestError>>, RequestError> {
+        let request = crate::TransactionRequestVariant::GetEdges(q.into().into());
+        #[cfg(feature = "metrics")]
+        let (op, call_start) = {
+            let op = request_variant_name(&request);
+            (op, self.metrics.as_ref().map(|m| m.start_call(op)))
+        };
+        let rx = self.send_multi(request).await?;
+        let stream = UnboundedReceiverStream::new(rx).map(|response| Ok(response?.try_into()?));
+        #[cfg(feature = "metrics")]
+        let stream = instrument_stream(stream, self.metrics.clone(), op, call_start);
+        Ok(stream)
+    }
+
     /// Deletes a set of edges specified by a query.
     ///
     /// # Arguments
     /// * `q`: The query to run.
-    pub async fn delete_edges<Q: Into<indradb::EdgeQuery>>(&mut self, q: Q) -> Result<(), ClientError> {
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, q)))]
+    pub async fn delete_edges<Q: Into<indradb::EdgeQuery>>(&self, q: Q) -> Result<(), RequestError> {
         let request = crate::TransactionRequestVariant::DeleteEdges(q.into().into());
         Ok(self.request_single(request).await?.try_into()?)
     }
@@ -348,12 +1429,13 @@ impl Transaction {
     /// * `id`: The id of the vertex.
     /// * `t`: Only get the count for a specified edge type.
     /// * `direction`: The direction of edges to get.
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, id, t, direction)))]
     pub async fn get_edge_count(
-        &mut self,
+        &self,
         id: Uuid,
         t: Option<&indradb::Identifier>,
         direction: indradb::EdgeDirection,
-    ) -> Result<u64, ClientError> {
+    ) -> Result<u64, RequestError> {
         let request = crate::TransactionRequestVariant::GetEdgeCount((id, t.cloned(), direction).into());
         Ok(self.request_single(request).await?.try_into()?)
     }
@@ -362,10 +1444,11 @@ impl Transaction {
     ///
     /// # Arguments
     /// * `q`: The query to run.
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, q)))]
     pub async fn get_vertex_properties(
-        &mut self,
+        &self,
         q: indradb::VertexPropertyQuery,
-    ) -> Result<Vec<indradb::VertexProperty>, ClientError> {
+    ) -> Result<Vec<indradb::VertexProperty>, RequestError> {
         let request = crate::TransactionRequestVariant::GetVertexProperties(q.into());
         let result: Result<Vec<indradb::VertexProperty>, ConversionError> = self
             .request_multi(request)
@@ -380,10 +1463,11 @@ impl Transaction {
     ///
     /// # Arguments
     /// * `q`: The query to run.
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, q)))]
     pub async fn get_all_vertex_properties<Q: Into<indradb::VertexQuery>>(
-        &mut self,
+        &self,
         q: Q,
-    ) -> Result<Vec<indradb::VertexProperties>, ClientError> {
+    ) -> Result<Vec<indradb::VertexProperties>, RequestError> {
         let request = crate::TransactionRequestVariant::GetAllVertexProperties(q.into().into());
         let result: Result<Vec<indradb::VertexProperties>, ConversionError> = self
             .request_multi(request)
@@ -394,16 +1478,63 @@ impl Transaction {
         Ok(result?)
     }
 
+    /// Gets vertex properties, yielding each one as it arrives instead of
+    /// buffering the whole result set into a `Vec`.
+    ///
+    /// # Arguments
+    /// * `q`: The query to run.
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, q)))]
+    pub async fn get_vertex_properties_stream(
+        &self,
+        q: indradb::VertexPropertyQuery,
+    ) -> Result<impl Stream<Item = Result<indradb::VertexProperty, RequestError>>, RequestError> {
+        let request = crate::TransactionRequestVariant::GetVertexProperties(q.into());
+        #[cfg(feature = "metrics")]
+        let (op, call_start) = {
+            let op = request_variant_name(&request);
+            (op, self.metrics.as_ref().map(|m| m.start_call(op)))
+        };
+        let rx = self.send_multi(request).await?;
+        let stream = UnboundedReceiverStream::new(rx).map(|response| Ok(response?.try_into()?));
+        #[cfg(feature = "metrics")]
+        let stream = instrument_stream(stream, self.metrics.clone(), op, call_start);
+        Ok(stream)
+    }
+
+    /// Gets all vertex properties, yielding each one as it arrives instead of
+    /// buffering the whole result set into a `Vec`.
+    ///
+    /// # Arguments
+    /// * `q`: The query to run.
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, q)))]
+    pub async fn get_all_vertex_properties_stream<Q: Into<indradb::VertexQuery>>(
+        &self,
+        q: Q,
+    ) -> Result<impl Stream<Item = Result<indradb::VertexProperties, RequestError>>, RequestError> {
+        let request = crate::TransactionRequestVariant::GetAllVertexProperties(q.into().into());
+        #[cfg(feature = "metrics")]
+        let (op, call_start) = {
+            let op = request_variant_name(&request);
+            (op, self.metrics.as_ref().map(|m| m.start_call(op)))
+        };
+        let rx = self.send_multi(request).await?;
+        let stream = UnboundedReceiverStream::new(rx).map(|response| Ok(response?.try_into()?));
+        #[cfg(feature = "metrics")]
+        let stream = instrument_stream(stream, self.metrics.clone(), op, call_start);
+        Ok(stream)
+    }
+
     /// Sets a vertex properties.
     ///
     /// # Arguments
     /// * `q`: The query to run.
     /// * `value`: The property value.
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, q, value)))]
     pub async fn set_vertex_properties(
-        &mut self,
+        &self,
         q: indradb::VertexPropertyQuery,
         value: &indradb::JsonValue,
-    ) -> Result<(), ClientError> {
+    ) -> Result<(), RequestError> {
         let request = crate::TransactionRequestVariant::SetVertexProperties((q, value.clone()).into());
         Ok(self.request_single(request).await?.try_into()?)
     }
@@ -412,7 +1543,8 @@ impl Transaction {
     ///
     /// # Arguments
     /// * `q`: The query to run.
-    pub async fn delete_vertex_properties(&mut self, q: indradb::VertexPropertyQuery) -> Result<(), ClientError> {
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, q)))]
+    pub async fn delete_vertex_properties(&self, q: indradb::VertexPropertyQuery) -> Result<(), RequestError> {
         let request = crate::TransactionRequestVariant::DeleteVertexProperties(q.into());
         Ok(self.request_single(request).await?.try_into()?)
     }
@@ -421,10 +1553,11 @@ impl Transaction {
     ///
     /// # Arguments
     /// * `q`: The query to run.
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, q)))]
     pub async fn get_edge_properties(
-        &mut self,
+        &self,
         q: indradb::EdgePropertyQuery,
-    ) -> Result<Vec<indradb::EdgeProperty>, ClientError> {
+    ) -> Result<Vec<indradb::EdgeProperty>, RequestError> {
         let request = crate::TransactionRequestVariant::GetEdgeProperties(q.into());
         let result: Result<Vec<indradb::EdgeProperty>, ConversionError> = self
             .request_multi(request)
@@ -439,10 +1572,11 @@ impl Transaction {
     ///
     /// # Arguments
     /// * `q`: The query to run.
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, q)))]
     pub async fn get_all_edge_properties<Q: Into<indradb::EdgeQuery>>(
-        &mut self,
+        &self,
         q: Q,
-    ) -> Result<Vec<indradb::EdgeProperties>, ClientError> {
+    ) -> Result<Vec<indradb::EdgeProperties>, RequestError> {
         let request = crate::TransactionRequestVariant::GetAllEdgeProperties(q.into().into());
         let result: Result<Vec<indradb::EdgeProperties>, ConversionError> = self
             .request_multi(request)
@@ -453,16 +1587,63 @@ impl Transaction {
         Ok(result?)
     }
 
+    /// Gets edge properties, yielding each one as it arrives instead of
+    /// buffering the whole result set into a `Vec`.
+    ///
+    /// # Arguments
+    /// * `q`: The query to run.
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, q)))]
+    pub async fn get_edge_properties_stream(
+        &self,
+        q: indradb::EdgePropertyQuery,
+    ) -> Result<impl Stream<Item = Result<indradb::EdgeProperty, RequestError>>, RequestError> {
+        let request = crate::TransactionRequestVariant::GetEdgeProperties(q.into());
+        #[cfg(feature = "metrics")]
+        let (op, call_start) = {
+            let op = request_variant_name(&request);
+            (op, self.metrics.as_ref().map(|m| m.start_call(op)))
+        };
+        let rx = self.send_multi(request).await?;
+        let stream = UnboundedReceiverStream::new(rx).map(|response| Ok(response?.try_into()?));
+        #[cfg(feature = "metrics")]
+        let stream = instrument_stream(stream, self.metrics.clone(), op, call_start);
+        Ok(stream)
+    }
+
+    /// Gets all edge properties, yielding each one as it arrives instead of
+    /// buffering the whole result set into a `Vec`.
+    ///
+    /// # Arguments
+    /// * `q`: The query to run.
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, q)))]
+    pub async fn get_all_edge_properties_stream<Q: Into<indradb::EdgeQuery>>(
+        &self,
+        q: Q,
+    ) -> Result<impl Stream<Item = Result<indradb::EdgeProperties, RequestError>>, RequestError> {
+        let request = crate::TransactionRequestVariant::GetAllEdgeProperties(q.into().into());
+        #[cfg(feature = "metrics")]
+        let (op, call_start) = {
+            let op = request_variant_name(&request);
+            (op, self.metrics.as_ref().map(|m| m.start_call(op)))
+        };
+        let rx = self.send_multi(request).await?;
+        let stream = UnboundedReceiverStream::new(rx).map(|response| Ok(response?.try_into()?));
+        #[cfg(feature = "metrics")]
+        let stream = instrument_stream(stream, self.metrics.clone(), op, call_start);
+        Ok(stream)
+    }
+
     /// Sets edge properties.
     ///
     /// # Arguments
     /// * `q`: The query to run.
     /// * `value`: The property value.
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, q, value)))]
     pub async fn set_edge_properties(
-        &mut self,
+        &self,
         q: indradb::EdgePropertyQuery,
         value: &indradb::JsonValue,
-    ) -> Result<(), ClientError> {
+    ) -> Result<(), RequestError> {
         let request = crate::TransactionRequestVariant::SetEdgeProperties((q, value.clone()).into());
         Ok(self.request_single(request).await?.try_into()?)
     }
@@ -471,8 +1652,465 @@ impl Transaction {
     ///
     /// # Arguments
     /// * `q`: The query to run.
-    pub async fn delete_edge_properties(&mut self, q: indradb::EdgePropertyQuery) -> Result<(), ClientError> {
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, q)))]
+    pub async fn delete_edge_properties(&self, q: indradb::EdgePropertyQuery) -> Result<(), RequestError> {
         let request = crate::TransactionRequestVariant::DeleteEdgeProperties(q.into());
         Ok(self.request_single(request).await?.try_into()?)
     }
 }
+
+/// One result out of a `Pipeline::execute` call, tagged by which operation
+/// produced it.
+pub enum PipelineItem {
+    CreateVertex(bool),
+    CreateVertexFromType(Uuid),
+    GetVertices(Vec<indradb::Vertex>),
+    DeleteVertices(()),
+    GetVertexCount(u64),
+    CreateEdge(bool),
+    GetEdges(Vec<indradb::Edge>),
+    DeleteEdges(()),
+    GetEdgeCount(u64),
+    GetVertexProperties(Vec<indradb::VertexProperty>),
+    GetAllVertexProperties(Vec<indradb::VertexProperties>),
+    SetVertexProperties(()),
+    DeleteVertexProperties(()),
+    GetEdgeProperties(Vec<indradb::EdgeProperty>),
+    GetAllEdgeProperties(Vec<indradb::EdgeProperties>),
+    SetEdgeProperties(()),
+    DeleteEdgeProperties(()),
+}
+
+type PipelineFuture<'a> = Pin<Box<dyn Future<Output = Result<PipelineItem, RequestError>> + Send + 'a>>;
+
+/// A builder for a batch of `Transaction` operations, returned by
+/// `Transaction::pipeline`. See that method for details.
+pub struct Pipeline<'a> {
+    transaction: &'a Transaction,
+    futures: Vec<PipelineFuture<'a>>,
+}
+
+macro_rules! pipeline_single_method {
+    ($name:ident, $item:ident, $request:expr, $($arg:ident: $arg_ty:ty),*) => {
+        #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, $($arg),*)))]
+        pub async fn $name(&mut self, $($arg: $arg_ty),*) -> Result<&mut Self, RequestError> {
+            let request = $request;
+            #[cfg(feature = "metrics")]
+            let mut call_metrics = PipelineCallMetrics::start(self.transaction.metrics.clone(), request_variant_name(&request));
+            let rx = self.transaction.send_single(request).await?;
+            self.futures.push(Box::pin(async move {
+                let result = async {
+                    let response = rx.await.map_err(|_| RequestError::ChannelClosed)??;
+                    Ok(PipelineItem::$item(response.try_into()?))
+                }
+                .await;
+                #[cfg(feature = "metrics")]
+                call_metrics.finish(request_outcome(&result));
+                result
+            }));
+            Ok(self)
+        }
+    };
+}
+
+macro_rules! pipeline_multi_method {
+    ($name:ident, $item:ident, $request:expr, $($arg:ident: $arg_ty:ty),*) => {
+        #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, $($arg),*)))]
+        pub async fn $name(&mut self, $($arg: $arg_ty),*) -> Result<&mut Self, RequestError> {
+            let request = $request;
+            #[cfg(feature = "metrics")]
+            let mut call_metrics = PipelineCallMetrics::start(self.transaction.metrics.clone(), request_variant_name(&request));
+            let mut rx = self.transaction.send_multi(request).await?;
+            self.futures.push(Box::pin(async move {
+                let result = async {
+                    let mut values = Vec::default();
+                    while let Some(response) = rx.recv().await {
+                        values.push(response?.try_into()?);
+                    }
+                    Ok(values)
+                }
+                .await;
+                #[cfg(feature = "metrics")]
+                {
+                    call_metrics.finish(request_outcome(&result));
+                    if let Ok(ref values) = result {
+                        if let Some(metrics) = &call_metrics.metrics {
+                            metrics.record_items_streamed(call_metrics.op, values.len() as u64);
+                        }
+                    }
+                }
+                result.map(PipelineItem::$item)
+            }));
+            Ok(self)
+        }
+    };
+}
+
+impl<'a> Pipeline<'a> {
+    pipeline_single_method!(
+        create_vertex,
+        CreateVertex,
+        crate::TransactionRequestVariant::CreateVertex(vertex.clone().into()),
+        vertex: &indradb::Vertex
+    );
+
+    pipeline_single_method!(
+        create_vertex_from_type,
+        CreateVertexFromType,
+        crate::TransactionRequestVariant::CreateVertexFromType(t.into()),
+        t: indradb::Identifier
+    );
+
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, q)))]
+    pub async fn get_vertices<Q: Into<indradb::VertexQuery>>(&mut self, q: Q) -> Result<&mut Self, RequestError> {
+        let request = crate::TransactionRequestVariant::GetVertices(q.into().into());
+        #[cfg(feature = "metrics")]
+        let mut call_metrics = PipelineCallMetrics::start(self.transaction.metrics.clone(), request_variant_name(&request));
+        let mut rx = self.transaction.send_multi(request).await?;
+        self.futures.push(Box::pin(async move {
+            let result = async {
+                let mut values = Vec::default();
+                while let Some(response) = rx.recv().await {
+                    values.push(response?.try_into()?);
+                }
+                Ok(values)
+            }
+            .await;
+            #[cfg(feature = "metrics")]
+            {
+                call_metrics.finish(request_outcome(&result));
+                if let Ok(ref values) = result {
+                    if let Some(metrics) = &call_metrics.metrics {
+                        metrics.record_items_streamed(call_metrics.op, values.len() as u64);
+                    }
+                }
+            }
+            result.map(PipelineItem::GetVertices)
+        }));
+        Ok(self)
+    }
+
+    pipeline_single_method!(
+        delete_vertices,
+        DeleteVertices,
+        crate::TransactionRequestVariant::DeleteVertices(q.into().into()),
+        q: indradb::VertexQuery
+    );
+
+    pipeline_single_method!(
+        get_vertex_count,
+        GetVertexCount,
+        crate::TransactionRequestVariant::GetVertexCount(()),
+    );
+
+    pipeline_single_method!(
+        create_edge,
+        CreateEdge,
+        crate::TransactionRequestVariant::CreateEdge(key.clone().into()),
+        key: &indradb::EdgeKey
+    );
+
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, q)))]
+    pub async fn get_edges<Q: Into<indradb::EdgeQuery>>(&mut self, q: Q) -> Result<&mut Self, RequestError> {
+        let request = crate::TransactionRequestVariant::GetEdges(q.into().into());
+        #[cfg(feature = "metrics")]
+        let mut call_metrics = PipelineCallMetrics::start(self.transaction.metrics.clone(), request_variant_name(&request));
+        let mut rx = self.transaction.send_multi(request).await?;
+        self.futures.push(Box::pin(async move {
+            let result = async {
+                let mut values = Vec::default();
+                while let Some(response) = rx.recv().await {
+                    values.push(response?.try_into()?);
+                }
+                Ok(values)
+            }
+            .await;
+            #[cfg(feature = "metrics")]
+            {
+                call_metrics.finish(request_outcome(&result));
+                if let Ok(ref values) = result {
+                    if let Some(metrics) = &call_metrics.metrics {
+                        metrics.record_items_streamed(call_metrics.op, values.len() as u64);
+                    }
+                }
+            }
+            result.map(PipelineItem::GetEdges)
+        }));
+        Ok(self)
+    }
+
+    pipeline_single_method!(
+        delete_edges,
+        DeleteEdges,
+        crate::TransactionRequestVariant::DeleteEdges(q.into().into()),
+        q: indradb::EdgeQuery
+    );
+
+    pipeline_single_method!(
+        get_edge_count,
+        GetEdgeCount,
+        crate::TransactionRequestVariant::GetEdgeCount((id, t, direction).into()),
+        id: Uuid,
+        t: Option<indradb::Identifier>,
+        direction: indradb::EdgeDirection
+    );
+
+    pipeline_multi_method!(
+        get_vertex_properties,
+        GetVertexProperties,
+        crate::TransactionRequestVariant::GetVertexProperties(q.into()),
+        q: indradb::VertexPropertyQuery
+    );
+
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, q)))]
+    pub async fn get_all_vertex_properties<Q: Into<indradb::VertexQuery>>(
+        &mut self,
+        q: Q,
+    ) -> Result<&mut Self, RequestError> {
+        let request = crate::TransactionRequestVariant::GetAllVertexProperties(q.into().into());
+        #[cfg(feature = "metrics")]
+        let mut call_metrics = PipelineCallMetrics::start(self.transaction.metrics.clone(), request_variant_name(&request));
+        let mut rx = self.transaction.send_multi(request).await?;
+        self.futures.push(Box::pin(async move {
+            let result = async {
+                let mut values = Vec::default();
+                while let Some(response) = rx.recv().await {
+                    values.push(response?.try_into()?);
+                }
+                Ok(values)
+            }
+            .await;
+            #[cfg(feature = "metrics")]
+            {
+                call_metrics.finish(request_outcome(&result));
+                if let Ok(ref values) = result {
+                    if let Some(metrics) = &call_metrics.metrics {
+                        metrics.record_items_streamed(call_metrics.op, values.len() as u64);
+                    }
+                }
+            }
+            result.map(PipelineItem::GetAllVertexProperties)
+        }));
+        Ok(self)
+    }
+
+    pipeline_single_method!(
+        set_vertex_properties,
+        SetVertexProperties,
+        crate::TransactionRequestVariant::SetVertexProperties((q, value.clone()).into()),
+        q: indradb::VertexPropertyQuery,
+        value: &indradb::JsonValue
+    );
+
+    pipeline_single_method!(
+        delete_vertex_properties,
+        DeleteVertexProperties,
+        crate::TransactionRequestVariant::DeleteVertexProperties(q.into()),
+        q: indradb::VertexPropertyQuery
+    );
+
+    pipeline_multi_method!(
+        get_edge_properties,
+        GetEdgeProperties,
+        crate::TransactionRequestVariant::GetEdgeProperties(q.into()),
+        q: indradb::EdgePropertyQuery
+    );
+
+    #[cfg_attr(feature = "metrics", tracing::instrument(skip(self, q)))]
+    pub async fn get_all_edge_properties<Q: Into<indradb::EdgeQuery>>(&mut self, q: Q) -> Result<&mut Self, RequestError> {
+        let request = crate::TransactionRequestVariant::GetAllEdgeProperties(q.into().into());
+        #[cfg(feature = "metrics")]
+        let mut call_metrics = PipelineCallMetrics::start(self.transaction.metrics.clone(), request_variant_name(&request));
+        let mut rx = self.transaction.send_multi(request).await?;
+        self.futures.push(Box::pin(async move {
+            let result = async {
+                let mut values = Vec::default();
+                while let Some(response) = rx.recv().await {
+                    values.push(response?.try_into()?);
+                }
+                Ok(values)
+            }
+            .await;
+            #[cfg(feature = "metrics")]
+            {
+                call_metrics.finish(request_outcome(&result));
+                if let Ok(ref values) = result {
+                    if let Some(metrics) = &call_metrics.metrics {
+                        metrics.record_items_streamed(call_metrics.op, values.len() as u64);
+                    }
+                }
+            }
+            result.map(PipelineItem::GetAllEdgeProperties)
+        }));
+        Ok(self)
+    }
+
+    pipeline_single_method!(
+        set_edge_properties,
+        SetEdgeProperties,
+        crate::TransactionRequestVariant::SetEdgeProperties((q, value.clone()).into()),
+        q: indradb::EdgePropertyQuery,
+        value: &indradb::JsonValue
+    );
+
+    pipeline_single_method!(
+        delete_edge_properties,
+        DeleteEdgeProperties,
+        crate::TransactionRequestVariant::DeleteEdgeProperties(q.into()),
+        q: indradb::EdgePropertyQuery
+    );
+
+    /// Awaits every enqueued operation's response and returns them in the
+    /// order they were enqueued, regardless of the order they actually came
+    /// back from the server in.
+    pub async fn execute(self) -> Vec<Result<PipelineItem, RequestError>> {
+        let mut results = Vec::with_capacity(self.futures.len());
+        for future in self.futures {
+            results.push(future.await);
+        }
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(jitter: bool) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 5,
+            initial_delay: std::time::Duration::from_millis(100),
+            backoff_multiplier: 2.0,
+            max_delay: std::time::Duration::from_secs(1),
+            jitter,
+        }
+    }
+
+    #[test]
+    fn is_retryable_classifies_transport_and_transient_grpc_errors() {
+        let policy = policy(false);
+        assert!(policy.is_retryable(&TransportError::ChannelClosed));
+        assert!(policy.is_retryable(&TransportError::Grpc {
+            inner: Status::new(tonic::Code::Unavailable, "down"),
+        }));
+        assert!(policy.is_retryable(&TransportError::Grpc {
+            inner: Status::new(tonic::Code::ResourceExhausted, "busy"),
+        }));
+        assert!(!policy.is_retryable(&TransportError::Grpc {
+            inner: Status::new(tonic::Code::InvalidArgument, "bad request"),
+        }));
+    }
+
+    #[test]
+    fn delay_for_attempt_backs_off_and_caps_at_max_delay() {
+        let policy = policy(false);
+        assert_eq!(policy.delay_for_attempt(0), std::time::Duration::from_millis(100));
+        assert_eq!(policy.delay_for_attempt(1), std::time::Duration::from_millis(200));
+        assert_eq!(policy.delay_for_attempt(2), std::time::Duration::from_millis(400));
+        // 100ms * 2^5 = 3.2s, which exceeds the 1s max_delay and should be capped.
+        assert_eq!(policy.delay_for_attempt(5), policy.max_delay);
+    }
+
+    #[tokio::test]
+    async fn demultiplexer_routes_a_response_to_its_single_waiter() {
+        let demux = Demultiplexer::default();
+        let rx = demux.register_single(7).await.unwrap();
+        demux.dispatch(7, Err(RequestError::ChannelClosed)).await;
+        assert!(matches!(rx.await.unwrap(), Err(RequestError::ChannelClosed)));
+    }
+
+    #[tokio::test]
+    async fn demultiplexer_drops_a_multi_responder_once_an_error_is_routed() {
+        let demux = Demultiplexer::default();
+        let mut rx = demux.register_multi(3).await.unwrap();
+        demux.dispatch(3, Err(RequestError::ChannelClosed)).await;
+        assert!(matches!(rx.recv().await, Some(Err(RequestError::ChannelClosed))));
+        // The entry was removed from `pending` rather than left to leak, so the
+        // channel closes instead of waiting on a response that'll never come.
+        assert!(rx.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn demultiplexer_removes_a_multi_responder_once_an_empty_sentinel_arrives() {
+        let demux = Demultiplexer::default();
+        let mut rx = demux.register_multi(4).await.unwrap();
+        demux
+            .dispatch(4, Ok(crate::TransactionResponseVariant::Empty(Default::default())))
+            .await;
+        // The `Empty` sentinel itself isn't forwarded to the caller - it's a
+        // signal that the stream is over, not a value in it.
+        assert!(!demux.state.lock().await.pending.contains_key(&4));
+        assert!(rx.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn demultiplexer_ignores_responses_for_unknown_or_already_finished_requests() {
+        let demux = Demultiplexer::default();
+        // No panic and nothing to route to for a request ID nobody registered.
+        demux.dispatch(42, Err(RequestError::ChannelClosed)).await;
+
+        let mut rx = demux.register_multi(9).await.unwrap();
+        demux.dispatch(9, Err(RequestError::ChannelClosed)).await;
+        assert!(rx.recv().await.is_some());
+        // The responder was already torn down by the error above; a second
+        // dispatch for the same ID has nobody left to hand the response to.
+        demux.dispatch(9, Err(RequestError::ChannelClosed)).await;
+        assert!(rx.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn fail_all_sends_a_terminal_error_to_single_and_multi_responders() {
+        let demux = Demultiplexer::default();
+        let single_rx = demux.register_single(1).await.unwrap();
+        let mut multi_rx = demux.register_multi(2).await.unwrap();
+
+        demux.fail_all(DemuxDeadReason::ChannelClosed).await;
+
+        assert!(matches!(single_rx.await.unwrap(), Err(RequestError::ChannelClosed)));
+        // A bare channel close reads identically to a normal end-of-stream to
+        // `request_multi`/the streaming variants, silently truncating the
+        // result set - `fail_all` must send a terminal `Err` instead.
+        assert!(matches!(multi_rx.recv().await, Some(Err(RequestError::ChannelClosed))));
+        assert!(multi_rx.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn fail_all_surfaces_an_error_even_after_some_items_were_already_forwarded() {
+        let demux = Demultiplexer::default();
+        let mut multi_rx = demux.register_multi(5).await.unwrap();
+
+        demux
+            .dispatch(5, Ok(crate::TransactionResponseVariant::CreateVertex(Default::default())))
+            .await;
+        demux.fail_all(DemuxDeadReason::ChannelClosed).await;
+
+        // The caller sees the item that already arrived, but the stream must
+        // end in an `Err` rather than a silent `None` - otherwise a
+        // connection drop mid-stream looks like a query that completed
+        // normally with a truncated result set.
+        assert!(matches!(
+            multi_rx.recv().await,
+            Some(Ok(crate::TransactionResponseVariant::CreateVertex(_)))
+        ));
+        assert!(matches!(multi_rx.recv().await, Some(Err(RequestError::ChannelClosed))));
+        assert!(multi_rx.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn register_fails_fast_once_the_demultiplexer_is_dead() {
+        let demux = Demultiplexer::default();
+        demux.fail_all(DemuxDeadReason::ChannelClosed).await;
+
+        // Without this, a call made after the background reader has already
+        // given up would register a responder nobody will ever drive,
+        // hanging forever instead of surfacing the same terminal error.
+        assert!(matches!(
+            demux.register_single(1).await,
+            Err(RequestError::ChannelClosed)
+        ));
+        assert!(matches!(
+            demux.register_multi(2).await,
+            Err(RequestError::ChannelClosed)
+        ));
+    }
+}